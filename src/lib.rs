@@ -1,73 +1,288 @@
-use tokio::time::{Duration, Instant};
+use std::time::Duration;
 
-#[derive(Clone, Copy)]
-pub struct Stopwatch {
+/// A monotonic clock source the [`Stopwatch`] can be built on top of.
+///
+/// Implementing this trait for a custom type (for example a mock clock in a
+/// test, or an embedded monotonic timer) lets the stopwatch be driven without
+/// touching the system clock at all.
+pub trait Instant: Copy {
+    /// Returns the current instant.
+    fn now() -> Self;
+    /// Returns the time elapsed since this instant was created.
+    fn elapsed(&self) -> Duration;
+    /// Returns the amount of time from `earlier` to `self`, or
+    /// [`None`](Option::None) if `earlier` is later than `self`.
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration>;
+}
+
+impl Instant for std::time::Instant {
+    fn now() -> Self {
+        std::time::Instant::now()
+    }
+
+    fn elapsed(&self) -> Duration {
+        std::time::Instant::elapsed(self)
+    }
+
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+        std::time::Instant::checked_duration_since(self, earlier)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Instant for tokio::time::Instant {
+    fn now() -> Self {
+        tokio::time::Instant::now()
+    }
+
+    fn elapsed(&self) -> Duration {
+        tokio::time::Instant::elapsed(self)
+    }
+
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+        tokio::time::Instant::checked_duration_since(self, earlier)
+    }
+}
+
+/// Unit a [`Stopwatch`] renders its elapsed time in when formatted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// Nanoseconds, e.g. `"1230000ns"`.
+    Nanos,
+    /// Microseconds, e.g. `"1230µs"`.
+    Micros,
+    /// Milliseconds, e.g. `"1230ms"`.
+    Millis,
+    /// Seconds with two fractional digits, e.g. `"1.23s"`.
+    Secs,
+    /// The largest unit with a non-zero value.
+    Auto,
+}
+
+impl TimeUnit {
+    /// Renders `d` in this unit.
+    fn render(self, d: Duration) -> String {
+        match self {
+            TimeUnit::Nanos => format!("{}ns", d.as_nanos()),
+            TimeUnit::Micros => format!("{}µs", d.as_micros()),
+            TimeUnit::Millis => format!("{}ms", d.as_millis()),
+            TimeUnit::Secs => format!("{:.2}s", d.as_secs_f64()),
+            TimeUnit::Auto => {
+                if d.as_secs() >= 1 {
+                    TimeUnit::Secs.render(d)
+                } else if d.as_millis() >= 1 {
+                    TimeUnit::Millis.render(d)
+                } else if d.as_micros() >= 1 {
+                    TimeUnit::Micros.render(d)
+                } else {
+                    TimeUnit::Nanos.render(d)
+                }
+            }
+        }
+    }
+}
+
+/// Full result of a timing run returned by [`Stopwatch::stop`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StopwatchData {
+    /// Total time measured across every running interval, scaled by the
+    /// stopwatch's speed factor.
+    pub elapsed: Duration,
+    /// Every lap recorded with [`Stopwatch::lap`], in the order they happened.
+    pub laps: Vec<Duration>,
+}
+
+#[derive(Clone)]
+pub struct Stopwatch<I: Instant = std::time::Instant> {
     /// Time of start of the stopwatch, ['None'](Option::None) if it has never started.
-    start_time: Option<Instant>,
+    start_time: Option<I>,
     /// Time of last time split.
-    last_split: Option<Instant>,
-    /// Total time elapsed from start to stop. Is 0 if stopped.
+    last_split: Option<I>,
+    /// Accumulated time from every interval that has already ended.
+    ///
+    /// The total reported time is `elapsed` plus whatever the current
+    /// running span (if any) has measured so far, so this stays correct
+    /// across repeated pause/resume cycles.
     elapsed: Duration,
+    /// Completed laps recorded with [`lap`](Stopwatch::lap).
+    laps: Vec<Duration>,
+    /// Factor every reported duration is multiplied by. Defaults to `1.0`.
+    ///
+    /// Values below `1.0` slow the reported clock down (e.g. `1.0 / 60.0`
+    /// reports minutes), values above `1.0` fast-forward it.
+    speed: f64,
+    /// Unit used by the [`Display`](std::fmt::Display) impl. Defaults to
+    /// [`TimeUnit::Millis`] to match the crate's original output.
+    format_unit: TimeUnit,
+    /// `true` only between a [`pause`](Stopwatch::pause) and the next
+    /// [`resume`](Stopwatch::resume)/[`start`](Stopwatch::start)/`stop`, so a
+    /// stopped watch is never mistaken for a paused one.
+    paused: bool,
 }
 
-impl Default for Stopwatch {
+/// The tokio-backed stopwatch, preserving the crate's original default clock
+/// for async callers.
+#[cfg(feature = "tokio")]
+pub type TokioStopwatch = Stopwatch<tokio::time::Instant>;
+
+impl<I: Instant> Default for Stopwatch<I> {
     fn default() -> Self {
         Stopwatch {
             start_time: None,
             last_split: None,
             elapsed: Duration::from_secs(0),
+            laps: Vec::new(),
+            speed: 1.0,
+            format_unit: TimeUnit::Millis,
+            paused: false,
         }
     }
 }
 
-impl std::fmt::Display for Stopwatch {
+impl<I: Instant> std::fmt::Display for Stopwatch<I> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}ms", self.elapsed.as_millis())
+        write!(f, "{}", self.format_unit.render(self.elapsed()))
     }
 }
 
-impl Stopwatch {
+impl<I: Instant> Stopwatch<I> {
     /// Returns an instance of a [`Stopwatch`] with default values.
-    pub fn new() -> Stopwatch {
+    pub fn new() -> Self {
         Default::default()
     }
 
     /// Begins the timing.
     pub fn start(&mut self) {
-        self.start_time = Some(Instant::now());
+        self.start_time = Some(I::now());
         self.last_split = None;
         self.elapsed = Duration::from_secs(0);
+        self.laps.clear();
+        self.paused = false;
     }
 
     /// Returnt an instance of a started [`Stopwatch`] at now.
-    pub fn start_new() -> Stopwatch {
-        let mut sw: Stopwatch = Default::default();
+    pub fn start_new() -> Self {
+        let mut sw: Self = Default::default();
         sw.start();
         sw
     }
 
-    /// Halts the timing.
+    /// Returns a fresh [`Stopwatch`] whose reported durations are scaled by
+    /// `speed`. A factor of `1.0 / 60.0` reports minutes, a factor above `1.0`
+    /// gives a fast-forward clock for simulations and game loops.
+    pub fn with_speed(speed: f64) -> Self {
+        Stopwatch {
+            speed,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the current speed factor.
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Sets the speed factor applied to every reported duration.
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = speed;
+    }
+
+    /// Returns the total elapsed time without mutating the stopwatch, scaled by
+    /// the configured [`speed`](Self::speed).
+    pub fn elapsed(&self) -> Duration {
+        let measured =
+            self.elapsed + self.start_time.map(|t| t.elapsed()).unwrap_or_default();
+        self.scale(measured)
+    }
+
+    /// Returns the current elapsed time rendered in the requested unit.
+    pub fn format_with(&self, unit: TimeUnit) -> String {
+        unit.render(self.elapsed())
+    }
+
+    /// Returns the unit used by the [`Display`](std::fmt::Display) impl.
+    pub fn format_unit(&self) -> TimeUnit {
+        self.format_unit
+    }
+
+    /// Sets the unit used by the [`Display`](std::fmt::Display) impl.
+    pub fn set_format_unit(&mut self, unit: TimeUnit) {
+        self.format_unit = unit;
+    }
+
+    /// Applies the speed factor to a measured duration, saturating at
+    /// [`Duration::MAX`] on overflow and clamping to zero for non-positive or
+    /// non-finite results.
+    fn scale(&self, measured: Duration) -> Duration {
+        let secs = measured.as_secs_f64() * self.speed;
+        if secs.is_finite() && secs > 0.0 {
+            Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+        } else {
+            Duration::from_secs(0)
+        }
+    }
+
+    /// Halts the timing and returns the full run, including every recorded lap.
     /// Does nothing if not started.
-    pub fn stop(&mut self) -> Duration{
-        match self.start_time {
-            Some(t1) => {
-                self.elapsed = t1.elapsed();
-                self.start_time = None;
-                self.last_split = None;
-                self.elapsed
-            },
-            None => Duration::from_secs(0),
+    pub fn stop(&mut self) -> StopwatchData {
+        if let Some(t1) = self.start_time {
+            self.elapsed += t1.elapsed();
+            self.start_time = None;
+            self.last_split = None;
+        }
+        self.paused = false;
+        StopwatchData {
+            elapsed: self.scale(self.elapsed),
+            laps: self.laps.clone(),
+        }
+    }
+
+    /// Suspends the timing, folding the current running span into the
+    /// accumulator so it can be continued later with [`resume`](Self::resume).
+    /// Does nothing if not running.
+    pub fn pause(&mut self) {
+        if let Some(t1) = self.start_time {
+            self.elapsed += t1.elapsed();
+            self.start_time = None;
+            self.paused = true;
+        }
+    }
+
+    /// Continues timing after a [`pause`](Self::pause) without discarding the
+    /// time already accumulated.
+    /// Does nothing if already running.
+    pub fn resume(&mut self) {
+        if self.start_time.is_none() {
+            self.start_time = Some(I::now());
+            self.paused = false;
         }
     }
 
-    /// Resets all values to default.
+    /// Returns `true` while the stopwatch is actively timing.
+    pub fn is_running(&self) -> bool {
+        self.start_time.is_some()
+    }
+
+    /// Returns `true` when the stopwatch has been paused and not yet resumed,
+    /// started over, or stopped. A stopped watch reports `false`.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Resets all values to default, preserving the configured speed factor
+    /// and display unit.
     pub fn reset(&mut self) {
-        *self = Default::default()
+        *self = Stopwatch {
+            speed: self.speed,
+            format_unit: self.format_unit,
+            ..Default::default()
+        };
     }
 
-    /// Resets values to default and starts timing again.
+    /// Resets values to default and starts timing again, preserving the
+    /// configured speed factor and display unit.
     pub fn restart(&mut self) {
-        *self = Default::default();
+        self.reset();
         self.start();
     }
 
@@ -75,30 +290,193 @@ impl Stopwatch {
     pub fn split(&mut self) -> Option<Duration> {
         match self.start_time {
             Some(t1) => {
-                self.last_split = Some(Instant::now());
-                self.elapsed = t1.elapsed();
-                Some(self.elapsed)
+                self.last_split = Some(I::now());
+                Some(self.scale(self.elapsed + t1.elapsed()))
             },
             None => None,
         }
     }
+
+    /// Records the time since the previous lap (or since start, for the first
+    /// lap) and appends it to the lap history. Laps are the raw measured
+    /// durations and are not scaled by [`speed`](Self::speed).
+    /// Returns [`None`](Option::None) if the stopwatch is not running.
+    pub fn lap(&mut self) -> Option<Duration> {
+        self.start_time.map(|t1| {
+            let now = I::now();
+            let delta = now
+                .checked_duration_since(self.last_split.unwrap_or(t1))
+                .unwrap_or_default();
+            self.last_split = Some(now);
+            self.laps.push(delta);
+            delta
+        })
+    }
+
+    /// Returns the laps recorded so far, allowing inspection while running.
+    /// These are the raw measured durations, matching [`lap`](Self::lap) and
+    /// [`StopwatchData::laps`] and unaffected by [`speed`](Self::speed).
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+}
+
+/// Runs `f` `iterations` times and returns the total measured duration.
+///
+/// A one-call micro-benchmarking primitive built on the crate's own
+/// `start`/`stop` machinery, without pulling in a full benchmarking framework.
+pub fn benchmark<F: FnMut()>(iterations: u32, mut f: F) -> Duration {
+    let mut sw = Stopwatch::<std::time::Instant>::start_new();
+    for _ in 0..iterations {
+        f();
+    }
+    sw.stop().elapsed
+}
+
+/// Benchmarks several named closures, each for `iterations` iterations, and
+/// returns `(name, total duration)` pairs in the same order as `fns`.
+///
+/// Only the total per closure is returned; per-iteration timings are not
+/// recorded. The names are carried through so results can be labelled at the
+/// call site.
+pub fn benchmark_many<'a>(
+    iterations: u32,
+    fns: &mut [(&'a str, &mut dyn FnMut())],
+) -> Vec<(&'a str, Duration)> {
+    fns.iter_mut()
+        .map(|(name, f)| (*name, benchmark(iterations, &mut **f)))
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
+    use std::cell::Cell;
     use std::{time::Duration, thread::sleep};
-    use super::Stopwatch;
+    use super::{benchmark, benchmark_many, Instant, Stopwatch, TimeUnit};
+
+    thread_local! {
+        /// A fully deterministic clock advanced by hand, used to time without
+        /// sleeping in the test suite. Each test thread has its own clock, so
+        /// tests running in parallel never advance each other's time.
+        static MOCK_NOW: Cell<u64> = Cell::new(0);
+    }
+
+    #[derive(Clone, Copy)]
+    struct MockInstant(u64);
+
+    impl MockInstant {
+        fn advance(nanos: u64) {
+            MOCK_NOW.with(|now| now.set(now.get() + nanos));
+        }
+    }
+
+    impl Instant for MockInstant {
+        fn now() -> Self {
+            MockInstant(MOCK_NOW.with(Cell::get))
+        }
+
+        fn elapsed(&self) -> Duration {
+            Duration::from_nanos(MOCK_NOW.with(Cell::get) - self.0)
+        }
+
+        fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+            self.0.checked_sub(earlier.0).map(Duration::from_nanos)
+        }
+    }
+
+    #[test]
+    fn test_mock_clock_drives_stopwatch_deterministically() {
+        let mut sw: Stopwatch<MockInstant> = Stopwatch::start_new();
+        MockInstant::advance(1_000);
+        sw.lap();
+        MockInstant::advance(2_000);
+        let data = sw.stop();
+        assert_eq!(data.elapsed, Duration::from_nanos(3_000));
+        assert_eq!(data.laps, vec![Duration::from_nanos(1_000)]);
+    }
+
+    #[test]
+    fn test_speed_scales_reported_durations() {
+        let mut sw: Stopwatch<MockInstant> = Stopwatch::with_speed(2.0);
+        assert_eq!(sw.speed(), 2.0);
+        sw.start();
+        MockInstant::advance(1_000);
+        assert_eq!(sw.stop().elapsed, Duration::from_nanos(2_000));
+    }
+
+    #[test]
+    fn test_benchmark_runs_closure_n_times() {
+        let mut count = 0u32;
+        benchmark(5, || count += 1);
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    fn test_benchmark_many_returns_one_duration_per_closure() {
+        let mut a = 0u32;
+        let mut b = 0u32;
+        let durations = {
+            let mut fa = || a += 1;
+            let mut fb = || b += 1;
+            let mut fns: [(&str, &mut dyn FnMut()); 2] =
+                [("a", &mut fa), ("b", &mut fb)];
+            benchmark_many(3, &mut fns)
+        };
+        assert_eq!(durations.len(), 2);
+        assert_eq!(durations[0].0, "a");
+        assert_eq!(durations[1].0, "b");
+        assert_eq!(a, 3);
+        assert_eq!(b, 3);
+    }
+
+    #[test]
+    fn test_elapsed_reads_live_without_mutating() {
+        let mut sw: Stopwatch<MockInstant> = Stopwatch::start_new();
+        MockInstant::advance(1_000);
+        assert_eq!(sw.elapsed(), Duration::from_nanos(1_000));
+        // Still running: elapsed() did not stop the stopwatch.
+        assert!(sw.is_running());
+        MockInstant::advance(1_000);
+        assert_eq!(sw.stop().elapsed, Duration::from_nanos(2_000));
+    }
+
+    #[test]
+    fn test_format_with_units() {
+        let d = Duration::from_millis(1_230);
+        assert_eq!(TimeUnit::Millis.render(d), "1230ms");
+        assert_eq!(TimeUnit::Secs.render(d), "1.23s");
+        assert_eq!(TimeUnit::Auto.render(d), "1.23s");
+        assert_eq!(TimeUnit::Auto.render(Duration::from_micros(5)), "5µs");
+    }
+
+    #[test]
+    fn test_display_uses_configured_unit() {
+        let mut sw: Stopwatch<MockInstant> = Stopwatch::start_new();
+        MockInstant::advance(1_500_000_000);
+        sw.set_format_unit(TimeUnit::Auto);
+        assert_eq!(sw.format_with(TimeUnit::Millis), "1500ms");
+        assert_eq!(sw.to_string(), "1.50s");
+    }
+
+    #[test]
+    fn test_reset_preserves_speed() {
+        let mut sw: Stopwatch<MockInstant> = Stopwatch::with_speed(0.5);
+        sw.start();
+        MockInstant::advance(1_000);
+        sw.reset();
+        assert_eq!(sw.speed(), 0.5);
+    }
 
     #[test]
     fn test_stopwatch_starts() {
-        let mut sw = Stopwatch::new();
+        let mut sw: Stopwatch = Stopwatch::new();
         sw.start();
         assert_ne!(sw.start_time, None);
     }
 
     #[test]
     fn test_start_new_sets_correct_values_and_starts() {
-        let sw = Stopwatch::start_new();
+        let sw: Stopwatch = Stopwatch::start_new();
         assert_ne!(sw.start_time, None);
         assert_eq!(sw.last_split, None);
         assert_eq!(sw.elapsed, Duration::from_secs(0));
@@ -106,7 +484,7 @@ mod test {
 
     #[test]
     fn test_new_sets_correct_values() {
-        let sw = Stopwatch::new();
+        let sw: Stopwatch = Stopwatch::new();
         assert_eq!(sw.start_time, None);
         assert_eq!(sw.last_split, None);
         assert_eq!(sw.elapsed, Duration::from_secs(0));
@@ -114,21 +492,21 @@ mod test {
 
     #[test]
     fn test_split_splits() {
-        let mut sw = Stopwatch::start_new();
+        let mut sw: Stopwatch = Stopwatch::start_new();
         sw.split();
         assert_ne!(sw.last_split, None);
     }
     
     #[test]
     fn test_split_dont_split_if_stopped() {
-        let mut sw = Stopwatch::new();
+        let mut sw: Stopwatch = Stopwatch::new();
         sw.split();
         assert_eq!(sw.last_split, None);
     }
 
     #[test]
     fn test_stop_resets_instants() {
-        let mut sw = Stopwatch::new();
+        let mut sw: Stopwatch = Stopwatch::new();
         sw.start();
         sw.stop();
         assert_eq!(sw.start_time, None);
@@ -137,7 +515,7 @@ mod test {
 
     #[test]
     fn test_stop_saves_elapsed_time() {
-        let mut sw = Stopwatch::new();
+        let mut sw: Stopwatch = Stopwatch::new();
         sw.start();
         sleep(Duration::from_millis(50));
         sw.stop();
@@ -146,7 +524,7 @@ mod test {
 
     #[test]
     fn test_reset_sets_correct_values() {
-        let mut sw = Stopwatch::start_new();
+        let mut sw: Stopwatch = Stopwatch::start_new();
         sw.split();
         sw.reset();
 
@@ -160,9 +538,93 @@ mod test {
         assert_eq!(sw.elapsed, Duration::from_secs(0));
     }
 
+    #[test]
+    fn test_pause_accumulates_and_resume_continues() {
+        let mut sw: Stopwatch = Stopwatch::start_new();
+        sleep(Duration::from_millis(20));
+        sw.pause();
+        let after_pause = sw.elapsed;
+        assert_eq!(sw.start_time, None);
+        assert!(after_pause.as_millis() >= 20);
+
+        // Time spent while paused is not counted.
+        sleep(Duration::from_millis(20));
+        assert_eq!(sw.elapsed, after_pause);
+
+        sw.resume();
+        assert_ne!(sw.start_time, None);
+        sleep(Duration::from_millis(20));
+        let total = sw.stop().elapsed;
+        assert!(total.as_millis() >= 40);
+    }
+
+    #[test]
+    fn test_lap_records_deltas_and_stop_returns_them() {
+        let mut sw: Stopwatch = Stopwatch::start_new();
+        sleep(Duration::from_millis(10));
+        sw.lap();
+        sleep(Duration::from_millis(10));
+        sw.lap();
+        assert_eq!(sw.laps().len(), 2);
+
+        let data = sw.stop();
+        assert_eq!(data.laps.len(), 2);
+        assert!(data.elapsed.as_millis() >= 20);
+    }
+
+    #[test]
+    fn test_start_clears_stale_laps() {
+        let mut sw: Stopwatch = Stopwatch::start_new();
+        sleep(Duration::from_millis(5));
+        sw.lap();
+        sw.stop();
+        sw.start();
+        assert!(sw.laps().is_empty());
+    }
+
+    #[test]
+    fn test_lap_does_nothing_if_stopped() {
+        let mut sw: Stopwatch = Stopwatch::new();
+        assert_eq!(sw.lap(), None);
+        assert!(sw.laps().is_empty());
+    }
+
+    #[test]
+    fn test_reset_clears_laps() {
+        let mut sw: Stopwatch = Stopwatch::start_new();
+        sw.lap();
+        sw.reset();
+        assert!(sw.laps().is_empty());
+    }
+
+    #[test]
+    fn test_is_running_and_is_paused() {
+        let mut sw: Stopwatch = Stopwatch::start_new();
+        assert!(sw.is_running());
+        assert!(!sw.is_paused());
+
+        sleep(Duration::from_millis(5));
+        sw.pause();
+        assert!(!sw.is_running());
+        assert!(sw.is_paused());
+
+        sw.reset();
+        assert!(!sw.is_running());
+        assert!(!sw.is_paused());
+    }
+
+    #[test]
+    fn test_stopped_watch_is_not_paused() {
+        let mut sw: Stopwatch = Stopwatch::start_new();
+        sleep(Duration::from_millis(5));
+        sw.stop();
+        assert!(!sw.is_running());
+        assert!(!sw.is_paused());
+    }
+
     #[test]
     fn test_restart_sets_correct_values_and_starts() {
-        let mut sw1 = Stopwatch::start_new();
+        let mut sw1: Stopwatch = Stopwatch::start_new();
         sw1.split();
 
         let mut sw2 = sw1.clone();